@@ -1,10 +1,11 @@
-use std::alloc::{alloc, dealloc, Layout};
+use std::alloc::{alloc, Layout};
 use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::{BuildHasher, Hash};
 use std::iter::Iterator;
 use std::marker;
+use std::mem;
 use std::ptr;
 
 #[derive(Debug)]
@@ -19,7 +20,7 @@ impl<T> Node<T> {
         let layout = Layout::new::<Node<T>>();
         let curr = alloc(layout) as *mut Node<T>;
         curr.write(Node {
-            value: value,
+            value,
             next: ptr::null_mut(),
             prev: ptr::null_mut(),
         });
@@ -27,16 +28,18 @@ impl<T> Node<T> {
         curr
     }
 
-    unsafe fn drop(curr: *mut Node<T>) {
-        let layout = Layout::new::<T>();
-        dealloc(curr as *mut u8, layout);
+    unsafe fn take(curr: *mut Node<T>) -> T {
+        let node = *Box::from_raw(curr);
+        node.value
     }
 }
 
 pub struct MutOrderedSet<T, S = RandomState> {
-    map: HashMap<u64, *mut Node<T>, S>,
+    map: HashMap<u64, Vec<*mut Node<T>>, S>,
     head: *mut Node<T>,
     tail: *mut Node<T>,
+    capacity: Option<usize>,
+    len: usize,
 }
 
 impl<T> MutOrderedSet<T, RandomState> {
@@ -45,6 +48,28 @@ impl<T> MutOrderedSet<T, RandomState> {
             map: HashMap::new(),
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
+            capacity: None,
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            capacity: None,
+            len: 0,
+        }
+    }
+
+    pub fn with_lru_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            capacity: Some(capacity),
+            len: 0,
         }
     }
 }
@@ -55,43 +80,168 @@ where
     S: BuildHasher,
 {
     #[inline]
-    pub fn insert(&mut self, value: T) -> &mut T {
+    pub fn insert(&mut self, value: T) -> Option<T> {
         let key = self.get_key(&value);
-        let curr = self.map.get(&key);
+        let bucket = self.map.entry(key).or_default();
+        let existing = bucket
+            .iter()
+            .find(|&&curr| unsafe { (*curr).value == value });
+
+        match existing {
+            Some(&curr) => {
+                unsafe {
+                    (*curr).value = value;
+                    self.detach(curr);
+                    self.attach(curr);
+                }
+
+                None
+            }
+            None => {
+                unsafe {
+                    let curr = Node::new(value);
+
+                    bucket.push(curr);
+                    self.attach(curr);
+                }
+
+                match self.capacity {
+                    Some(capacity) if self.len() > capacity => self.evict_lru(),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_refresh(&mut self, value: &T) -> Option<&mut T> {
+        let curr = self.find_node(value)?;
 
-        match curr {
-            Some(&curr) => unsafe {
-                (*curr).value = value;
+        unsafe {
+            self.detach(curr);
+            self.attach(curr);
 
-                &mut (*curr).value
-            },
-            None => unsafe {
-                let curr = Node::new(value);
+            Some(&mut (*curr).value)
+        }
+    }
 
-                self.map.insert(key, curr);
-                self.attach(curr);
+    pub fn set_lru_capacity(&mut self, capacity: usize) -> Vec<T> {
+        self.capacity = Some(capacity);
 
-                &mut (*curr).value
-            },
+        let mut evicted = Vec::new();
+        while self.len() > capacity {
+            match self.evict_lru() {
+                Some(value) => evicted.push(value),
+                None => break,
+            }
         }
+
+        evicted
     }
 
-    #[inline]
-    pub fn remove(&mut self, value: &T) -> bool {
-        let key = self.get_key(&value);
-        let curr = self.map.remove(&key);
+    pub fn to_front(&mut self, value: &T) -> bool {
+        self.relocate(value, false)
+    }
 
-        match curr {
-            Some(curr) => unsafe {
-                self.detach(curr);
-                Node::drop(curr);
+    pub fn to_back(&mut self, value: &T) -> bool {
+        self.relocate(value, true)
+    }
+
+    fn relocate(&mut self, value: &T, to_tail: bool) -> bool {
+        match self.find_node(value) {
+            Some(curr) => {
+                unsafe {
+                    self.detach(curr);
+                    if to_tail {
+                        self.attach(curr);
+                    } else {
+                        self.attach_front(curr);
+                    }
+                }
 
                 true
-            },
+            }
             None => false,
         }
     }
 
+    fn find_node(&self, value: &T) -> Option<*mut Node<T>> {
+        let key = self.get_key(value);
+
+        self.map
+            .get(&key)?
+            .iter()
+            .find(|&&curr| unsafe { (*curr).value == *value })
+            .copied()
+    }
+
+    fn evict_lru(&mut self) -> Option<T> {
+        let curr = self.head;
+        if curr.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let key = self.get_key(&(*curr).value);
+            self.detach(curr);
+
+            if let Some(bucket) = self.map.get_mut(&key) {
+                if let Some(pos) = bucket.iter().position(|&n| n == curr) {
+                    bucket.remove(pos);
+                }
+                if bucket.is_empty() {
+                    self.map.remove(&key);
+                }
+            }
+
+            Some(Node::take(curr))
+        }
+    }
+
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.take(value).is_some()
+    }
+
+    pub fn take(&mut self, value: &T) -> Option<T> {
+        let key = self.get_key(value);
+        let bucket = self.map.get_mut(&key)?;
+        let pos = bucket
+            .iter()
+            .position(|&curr| unsafe { (*curr).value == *value })?;
+        let curr = bucket.remove(pos);
+
+        if bucket.is_empty() {
+            self.map.remove(&key);
+        }
+
+        unsafe {
+            self.detach(curr);
+            Some(Node::take(curr))
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let key = self.get_key(value);
+
+        self.map.get(&key).is_some_and(|bucket| {
+            bucket.iter().any(|&curr| unsafe { (*curr).value == *value })
+        })
+    }
+
+    pub fn get(&self, value: &T) -> Option<&T> {
+        self.find_node(value)
+            .map(|curr| unsafe { &(*curr).value })
+    }
+
+    pub fn get_mut(&mut self, value: &T) -> Option<&mut T> {
+        self.find_node(value)
+            .map(|curr| unsafe { &mut (*curr).value })
+    }
+
+    pub fn clear(&mut self) {
+        while self.evict_lru().is_some() {}
+    }
+
     #[inline]
     pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for value in iter {
@@ -100,16 +250,13 @@ where
     }
 
     fn get_key(&self, value: &T) -> u64 {
-        use core::hash::Hasher;
-
-        let mut hasher = self.map.hasher().build_hasher();
-        value.hash(&mut hasher);
-        hasher.finish()
+        self.map.hasher().hash_one(value)
     }
 
     unsafe fn attach(&mut self, curr: *mut Node<T>) {
         // prev and next
         (*curr).prev = self.tail;
+        (*curr).next = ptr::null_mut();
         if !self.tail.is_null() {
             (*self.tail).next = curr;
         }
@@ -119,6 +266,23 @@ where
             self.head = curr;
         }
         self.tail = curr;
+        self.len += 1;
+    }
+
+    unsafe fn attach_front(&mut self, curr: *mut Node<T>) {
+        // prev and next
+        (*curr).next = self.head;
+        (*curr).prev = ptr::null_mut();
+        if !self.head.is_null() {
+            (*self.head).prev = curr;
+        }
+
+        // head and tail
+        if self.tail.is_null() {
+            self.tail = curr;
+        }
+        self.head = curr;
+        self.len += 1;
     }
 
     unsafe fn detach(&mut self, curr: *mut Node<T>) {
@@ -140,27 +304,79 @@ where
         if self.tail == curr {
             self.tail = prev;
         }
+        self.len -= 1;
+    }
+}
+
+impl<T, S> Drop for MutOrderedSet<T, S> {
+    fn drop(&mut self) {
+        let mut curr = self.head;
+        while !curr.is_null() {
+            unsafe {
+                let next = (*curr).next;
+                Node::take(curr);
+                curr = next;
+            }
+        }
     }
 }
 
 impl<T, S> MutOrderedSet<T, S> {
     pub fn len(&self) -> usize {
-        self.map.len()
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hasher),
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            capacity: None,
+            len: 0,
+        }
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            custer: self.head,
+            front: self.head,
+            back: self.tail,
             _marker: marker::PhantomData,
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
-            custer: self.head,
+            front: self.head,
+            back: self.tail,
             _marker: marker::PhantomData,
         }
     }
+
+    pub fn front(&self) -> Option<&T> {
+        if self.head.is_null() {
+            None
+        } else {
+            unsafe { Some(&(*self.head).value) }
+        }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.tail.is_null() {
+            None
+        } else {
+            unsafe { Some(&(*self.tail).value) }
+        }
+    }
+}
+
+impl<T> Default for MutOrderedSet<T, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T, S> IntoIterator for MutOrderedSet<T, S> {
@@ -169,12 +385,31 @@ impl<T, S> IntoIterator for MutOrderedSet<T, S> {
 
     #[inline]
     fn into_iter(self) -> IntoIter<T> {
-        IntoIter { custer: self.head }
+        // `MutOrderedSet`'s `Drop` frees nodes by walking the linked list,
+        // so the nodes must be handed off to `IntoIter` without letting it
+        // run. `ManuallyDrop` suppresses that while we still drop the map
+        // itself (it only owns the bucket `Vec`s, not the node memory).
+        let mut this = mem::ManuallyDrop::new(self);
+        let front = this.head;
+        let back = this.tail;
+
+        unsafe {
+            ptr::drop_in_place(&mut this.map);
+        }
+
+        IntoIter { front, back }
     }
 }
 
 pub struct IntoIter<T> {
-    custer: *mut Node<T>,
+    front: *mut Node<T>,
+    back: *mut Node<T>,
+}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
 }
 
 impl<T> Iterator for IntoIter<T> {
@@ -182,21 +417,50 @@ impl<T> Iterator for IntoIter<T> {
 
     #[inline]
     fn next(&mut self) -> Option<T> {
-        if self.custer != ptr::null_mut() {
-            unsafe {
-                let node = *Box::from_raw(self.custer);
-                self.custer = node.next;
+        if self.front.is_null() {
+            return None;
+        }
 
-                Some(node.value)
+        unsafe {
+            let node = *Box::from_raw(self.front);
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.front = node.next;
             }
-        } else {
-            None
+
+            Some(node.value)
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.back.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let node = *Box::from_raw(self.back);
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.back = node.prev;
+            }
+
+            Some(node.value)
         }
     }
 }
 
 pub struct Iter<'a, T> {
-    custer: *mut Node<T>,
+    front: *mut Node<T>,
+    back: *mut Node<T>,
     _marker: marker::PhantomData<&'a T>,
 }
 
@@ -204,20 +468,49 @@ impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
-        if !self.custer.is_null() {
-            unsafe {
-                let r = Some(&(*self.custer).value);
-                self.custer = (*self.custer).next;
-                r
+        if self.front.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let r = &(*self.front).value;
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.front = (*self.front).next;
             }
-        } else {
-            None
+
+            Some(r)
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.back.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let r = &(*self.back).value;
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.back = (*self.back).prev;
+            }
+
+            Some(r)
         }
     }
 }
 
 pub struct IterMut<'a, T> {
-    custer: *mut Node<T>,
+    front: *mut Node<T>,
+    back: *mut Node<T>,
     _marker: marker::PhantomData<&'a T>,
 }
 
@@ -225,14 +518,42 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<&'a mut T> {
-        if !self.custer.is_null() {
-            unsafe {
-                let r = Some(&mut (*self.custer).value);
-                self.custer = (*self.custer).next;
-                r
+        if self.front.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let r = &mut (*self.front).value;
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.front = (*self.front).next;
             }
-        } else {
-            None
+
+            Some(r)
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.back.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let r = &mut (*self.back).value;
+
+            if self.front == self.back {
+                self.front = ptr::null_mut();
+                self.back = ptr::null_mut();
+            } else {
+                self.back = (*self.back).prev;
+            }
+
+            Some(r)
         }
     }
 }
@@ -242,10 +563,11 @@ where
     T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let nodes = self
-            .map
-            .iter()
-            .map(|(k, v)| unsafe { (k, &**v as &Node<T>) });
+        let nodes = self.map.iter().flat_map(|(k, bucket)| {
+            bucket
+                .iter()
+                .map(move |&curr| unsafe { (k, &*curr as &Node<T>) })
+        });
 
         f.debug_map()
             .key(&"head")
@@ -257,6 +579,62 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T, S> serde::Serialize for MutOrderedSet<T, S>
+where
+    T: serde::Serialize,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, S> serde::Deserialize<'de> for MutOrderedSet<T, S>
+where
+    T: serde::Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SetVisitor<T, S> {
+            marker: marker::PhantomData<(T, S)>,
+        }
+
+        impl<'de, T, S> serde::de::Visitor<'de> for SetVisitor<T, S>
+        where
+            T: serde::Deserialize<'de> + Eq + Hash,
+            S: BuildHasher + Default,
+        {
+            type Value = MutOrderedSet<T, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence of elements")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut set = MutOrderedSet::with_hasher(S::default());
+                while let Some(value) = seq.next_element()? {
+                    set.insert(value);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(SetVisitor {
+            marker: marker::PhantomData,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,7 +648,7 @@ mod tests {
         set.insert(13);
         set.insert(12);
 
-        let set_v: Vec<i32> = set.into_iter().map(|x| x).collect();
+        let set_v: Vec<i32> = set.into_iter().collect();
         assert_eq!(set_v, [16, 1, 13, 12]);
     }
 
@@ -285,8 +663,10 @@ mod tests {
         set.insert(1);
         set.insert(16);
 
-        let set_v: Vec<i32> = set.into_iter().map(|x| x).collect();
-        assert_eq!(set_v, [16, 1, 13, 12]);
+        // Re-inserting an existing value counts as a use, so it moves to
+        // the back of the order just like `get_refresh` would.
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [13, 12, 1, 16]);
     }
 
     #[test]
@@ -302,8 +682,164 @@ mod tests {
         set.insert(13);
         set.insert(9);
 
-        let set_v: Vec<i32> = set.into_iter().map(|x| x).collect();
-        assert_eq!(set_v, [13, 16, 9]);
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [16, 13, 9]);
+    }
+
+    #[test]
+    fn contains() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(16);
+        set.insert(1);
+        set.remove(&16);
+
+        assert!(set.contains(&1));
+        assert!(!set.contains(&16));
+    }
+
+    #[test]
+    fn with_lru_capacity_evicts_lru() {
+        let mut set = MutOrderedSet::with_lru_capacity(2);
+
+        assert_eq!(set.insert(1), None);
+        assert_eq!(set.insert(2), None);
+        assert_eq!(set.insert(3), Some(1));
+
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [2, 3]);
+    }
+
+    #[test]
+    fn insert_existing_bumps_recency() {
+        let mut set = MutOrderedSet::with_lru_capacity(2);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(1);
+        assert_eq!(set.insert(3), Some(2));
+
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [1, 3]);
+    }
+
+    #[test]
+    fn get_refresh_bumps_recency() {
+        let mut set = MutOrderedSet::with_lru_capacity(2);
+
+        set.insert(1);
+        set.insert(2);
+        set.get_refresh(&1);
+        set.insert(3);
+
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [1, 3]);
+    }
+
+    #[test]
+    fn set_lru_capacity_shrinks_and_evicts() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        let evicted = set.set_lru_capacity(1);
+        assert_eq!(evicted, [1, 2]);
+
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [3]);
+    }
+
+    #[test]
+    fn to_front_and_to_back() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        set.to_front(&3);
+        set.to_back(&1);
+
+        let set_v: Vec<i32> = set.into_iter().collect();
+        assert_eq!(set_v, [3, 2, 1]);
+    }
+
+    #[test]
+    fn get_and_take() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(16);
+        set.insert(1);
+
+        assert_eq!(set.get(&16), Some(&16));
+        assert_eq!(set.get(&9), None);
+        assert_eq!(set.get_mut(&1), Some(&mut 1));
+
+        assert_eq!(set.take(&16), Some(16));
+        assert_eq!(set.take(&16), None);
+
+        assert!(!set.is_empty());
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn front_and_back() {
+        let mut set = MutOrderedSet::new();
+
+        assert_eq!(set.front(), None);
+        assert_eq!(set.back(), None);
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        assert_eq!(set.front(), Some(&1));
+        assert_eq!(set.back(), Some(&3));
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        let set_v: Vec<i32> = set.iter().rev().copied().collect();
+        assert_eq!(set_v, [3, 2, 1]);
+    }
+
+    #[test]
+    fn iter_meets_in_the_middle() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+        set.insert(4);
+
+        let mut iter = set.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let mut set = MutOrderedSet::new();
+
+        set.insert(1);
+        set.insert(2);
+        set.insert(3);
+
+        let set_v: Vec<i32> = set.into_iter().rev().collect();
+        assert_eq!(set_v, [3, 2, 1]);
     }
 
     #[test]
@@ -336,4 +872,57 @@ mod tests {
 
         assert_eq!(set_v, [&2, &10, &4]);
     }
+
+    struct DropCounter<'a>(i32, &'a std::cell::Cell<usize>);
+
+    impl PartialEq for DropCounter<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for DropCounter<'_> {}
+
+    impl std::hash::Hash for DropCounter<'_> {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    #[test]
+    fn drop_runs_for_every_element() {
+        let drops = std::cell::Cell::new(0);
+        let mut set = MutOrderedSet::new();
+
+        set.insert(DropCounter(1, &drops));
+        set.insert(DropCounter(2, &drops));
+        set.insert(DropCounter(3, &drops));
+
+        drop(set);
+
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn drop_runs_for_partially_consumed_into_iter() {
+        let drops = std::cell::Cell::new(0);
+        let mut set = MutOrderedSet::new();
+
+        set.insert(DropCounter(1, &drops));
+        set.insert(DropCounter(2, &drops));
+        set.insert(DropCounter(3, &drops));
+
+        let mut into_iter = set.into_iter();
+        into_iter.next();
+
+        drop(into_iter);
+
+        assert_eq!(drops.get(), 3);
+    }
 }
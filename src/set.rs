@@ -1,12 +1,24 @@
 use std::collections::hash_map;
-use std::collections::hash_map::{RandomState, Values, ValuesMut};
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::{BuildHasher, Hash};
-use std::iter::{Extend, Iterator};
+use std::iter::{Chain, Extend, FromIterator, Iterator};
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+use std::slice;
+use std::vec;
+
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{
+    IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelExtend,
+    ParallelIterator,
+};
 
 pub struct MutSet<T, S = RandomState> {
-    map: HashMap<u64, T, S>,
+    map: HashMap<u64, Vec<T>, S>,
 }
 
 impl<T> MutSet<T, RandomState> {
@@ -15,6 +27,12 @@ impl<T> MutSet<T, RandomState> {
             map: HashMap::new(),
         }
     }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+        }
+    }
 }
 
 impl<T, S> MutSet<T, S>
@@ -23,29 +41,181 @@ where
     S: BuildHasher,
 {
     pub fn insert(&mut self, value: T) -> bool {
-        use core::hash::Hasher;
+        let key = self.hash_of(&value);
+        let bucket = self.map.entry(key).or_default();
+
+        match bucket.iter_mut().find(|existing| **existing == value) {
+            Some(slot) => {
+                *slot = value;
+                false
+            }
+            None => {
+                bucket.push(value);
+                true
+            }
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let key = self.hash_of(value);
+
+        self.map
+            .get(&key)
+            .is_some_and(|bucket| bucket.iter().any(|existing| existing == value))
+    }
+
+    pub fn get(&self, value: &T) -> Option<&T> {
+        let key = self.hash_of(value);
+
+        self.map
+            .get(&key)?
+            .iter()
+            .find(|existing| *existing == value)
+    }
+
+    pub fn get_mut(&mut self, value: &T) -> Option<&mut T> {
+        let key = self.hash_of(value);
+
+        self.map
+            .get_mut(&key)?
+            .iter_mut()
+            .find(|existing| *existing == value)
+    }
 
-        let mut hasher = self.map.hasher().build_hasher();
-        value.hash(&mut hasher);
-        let key = hasher.finish();
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.take(value).is_some()
+    }
+
+    pub fn take(&mut self, value: &T) -> Option<T> {
+        let key = self.hash_of(value);
+        let bucket = self.map.get_mut(&key)?;
+        let pos = bucket.iter().position(|existing| existing == value)?;
+        let removed = bucket.remove(pos);
+
+        if bucket.is_empty() {
+            self.map.remove(&key);
+        }
+
+        Some(removed)
+    }
+
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.map.retain(|_, bucket| {
+            bucket.retain_mut(|value| f(value));
+            !bucket.is_empty()
+        });
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
 
-        self.map.insert(key, value).is_none()
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, S> {
+        Union {
+            iter: self.iter().chain(other.difference(self)),
+        }
+    }
+
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T, S> {
+        Intersection {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, S> {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T, S> {
+        SymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.iter().all(|value| other.contains(value))
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.iter().all(|value| !other.contains(value))
+    }
+
+    fn hash_of(&self, value: &T) -> u64 {
+        self.map.hasher().hash_one(value)
     }
 }
 
 impl<T, S> MutSet<T, S> {
     pub fn len(&self) -> usize {
-        self.map.len()
+        self.map.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
     }
 
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            iter: self.map.values(),
+            outer: self.map.values(),
+            inner: [].iter(),
         }
     }
 
-    pub fn iter_mut(&mut self) -> ValuesMut<'_, u64, T> {
-        self.map.values_mut()
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            outer: self.map.values_mut(),
+            inner: [].iter_mut(),
+        }
+    }
+
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hasher),
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hasher),
+        }
+    }
+}
+
+impl<T, S> Default for MutSet<T, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+        }
+    }
+}
+
+impl<T, S> FromIterator<T> for MutSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::with_hasher(S::default());
+        set.extend(iter);
+        set
     }
 }
 
@@ -56,15 +226,9 @@ where
 {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        use core::hash::Hasher;
-
-        let mut hasher = self.map.hasher().build_hasher();
-
-        self.map.extend(iter.into_iter().map(|value| {
-            value.hash(&mut hasher);
-            let key = hasher.finish();
-            (key, value)
-        }));
+        for value in iter {
+            self.insert(value);
+        }
     }
 }
 
@@ -85,7 +249,8 @@ impl<T, S> IntoIterator for MutSet<T, S> {
     #[inline]
     fn into_iter(self) -> IntoIter<T> {
         IntoIter {
-            iter: self.map.into_iter(),
+            outer: self.map.into_values(),
+            inner: Vec::new().into_iter(),
         }
     }
 }
@@ -98,36 +263,582 @@ where
         f.debug_set().entries(self.iter()).finish()
     }
 }
-pub struct Iter<'a, V: 'a> {
-    iter: Values<'a, u64, V>,
+
+pub struct Iter<'a, T: 'a> {
+    outer: hash_map::Values<'a, u64, Vec<T>>,
+    inner: slice::Iter<'a, T>,
 }
 
-impl<'a, V> Iterator for Iter<'a, V> {
-    type Item = &'a V;
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
 
-    #[inline]
-    fn next(&mut self) -> Option<&'a V> {
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(value) = self.inner.next() {
+                return Some(value);
+            }
+            self.inner = self.outer.next()?.iter();
+        }
+    }
+}
+
+pub struct IterMut<'a, T: 'a> {
+    outer: hash_map::ValuesMut<'a, u64, Vec<T>>,
+    inner: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(value) = self.inner.next() {
+                return Some(value);
+            }
+            self.inner = self.outer.next()?.iter_mut();
+        }
+    }
+}
+
+pub struct IntoIter<T> {
+    outer: hash_map::IntoValues<u64, Vec<T>>,
+    inner: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(value) = self.inner.next() {
+                return Some(value);
+            }
+            self.inner = self.outer.next()?.into_iter();
+        }
+    }
+}
+
+pub struct Intersection<'a, T: 'a, S: 'a> {
+    iter: Iter<'a, T>,
+    other: &'a MutSet<T, S>,
+}
+
+impl<'a, T, S> Iterator for Intersection<'a, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let value = self.iter.next()?;
+            if self.other.contains(value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+pub struct Difference<'a, T: 'a, S: 'a> {
+    iter: Iter<'a, T>,
+    other: &'a MutSet<T, S>,
+}
+
+impl<'a, T, S> Iterator for Difference<'a, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let value = self.iter.next()?;
+            if !self.other.contains(value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+pub struct SymmetricDifference<'a, T: 'a, S: 'a> {
+    iter: Chain<Difference<'a, T, S>, Difference<'a, T, S>>,
+}
+
+impl<'a, T, S> Iterator for SymmetricDifference<'a, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
         self.iter.next()
     }
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+}
+
+pub struct Union<'a, T: 'a, S: 'a> {
+    iter: Chain<Iter<'a, T>, Difference<'a, T, S>>,
+}
+
+impl<'a, T, S> Iterator for Union<'a, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
     }
 }
 
-pub struct IntoIter<V> {
-    iter: hash_map::IntoIter<u64, V>,
+impl<T, S> BitOr<&MutSet<T, S>> for &MutSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = MutSet<T, S>;
+
+    fn bitor(self, rhs: &MutSet<T, S>) -> MutSet<T, S> {
+        self.union(rhs).cloned().collect()
+    }
 }
 
-impl<V> Iterator for IntoIter<V> {
-    type Item = V;
+impl<T, S> BitAnd<&MutSet<T, S>> for &MutSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = MutSet<T, S>;
 
-    #[inline]
-    fn next(&mut self) -> Option<V> {
-        self.iter.next().map(|(_, v)| v)
+    fn bitand(self, rhs: &MutSet<T, S>) -> MutSet<T, S> {
+        self.intersection(rhs).cloned().collect()
     }
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+}
+
+impl<T, S> Sub<&MutSet<T, S>> for &MutSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = MutSet<T, S>;
+
+    fn sub(self, rhs: &MutSet<T, S>) -> MutSet<T, S> {
+        self.difference(rhs).cloned().collect()
+    }
+}
+
+impl<T, S> BitXor<&MutSet<T, S>> for &MutSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = MutSet<T, S>;
+
+    fn bitxor(self, rhs: &MutSet<T, S>) -> MutSet<T, S> {
+        self.symmetric_difference(rhs).cloned().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, S> serde::Serialize for MutSet<T, S>
+where
+    T: serde::Serialize,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, S> serde::Deserialize<'de> for MutSet<T, S>
+where
+    T: serde::Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SetVisitor<T, S> {
+            marker: PhantomData<(T, S)>,
+        }
+
+        impl<'de, T, S> serde::de::Visitor<'de> for SetVisitor<T, S>
+        where
+            T: serde::Deserialize<'de> + Eq + Hash,
+            S: BuildHasher + Default,
+        {
+            type Value = MutSet<T, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence of elements")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut set = MutSet::with_hasher(S::default());
+                while let Some(value) = seq.next_element()? {
+                    set.insert(value);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(SetVisitor {
+            marker: PhantomData,
+        })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "rayon")]
+impl<T, S> IntoParallelIterator for MutSet<T, S>
+where
+    T: Send,
+    S: BuildHasher + Send,
+{
+    type Item = T;
+    type Iter = rayon::iter::FlatMap<
+        rayon::collections::hash_map::IntoIter<u64, Vec<T>>,
+        fn((u64, Vec<T>)) -> rayon::vec::IntoIter<T>,
+    >;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.map.into_par_iter().flat_map(
+            (|(_, bucket): (u64, Vec<T>)| bucket.into_par_iter())
+                as fn((u64, Vec<T>)) -> rayon::vec::IntoIter<T>,
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: 'a, S> IntoParallelRefIterator<'a> for MutSet<T, S>
+where
+    T: Sync,
+    S: BuildHasher + Sync,
+{
+    type Item = &'a T;
+    type Iter = rayon::iter::FlatMap<
+        rayon::collections::hash_map::Iter<'a, u64, Vec<T>>,
+        fn((&'a u64, &'a Vec<T>)) -> rayon::slice::Iter<'a, T>,
+    >;
+
+    fn par_iter(&'a self) -> Self::Iter {
+        self.map.par_iter().flat_map(
+            (|(_, bucket): (&'a u64, &'a Vec<T>)| bucket.par_iter())
+                as fn((&'a u64, &'a Vec<T>)) -> rayon::slice::Iter<'a, T>,
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: 'a, S> IntoParallelRefMutIterator<'a> for MutSet<T, S>
+where
+    T: Send,
+    S: BuildHasher + Send,
+{
+    type Item = &'a mut T;
+    type Iter = rayon::iter::FlatMap<
+        rayon::collections::hash_map::IterMut<'a, u64, Vec<T>>,
+        fn((&'a u64, &'a mut Vec<T>)) -> rayon::slice::IterMut<'a, T>,
+    >;
+
+    fn par_iter_mut(&'a mut self) -> Self::Iter {
+        self.map.par_iter_mut().flat_map(
+            (|(_, bucket): (&'a u64, &'a mut Vec<T>)| bucket.par_iter_mut())
+                as fn((&'a u64, &'a mut Vec<T>)) -> rayon::slice::IterMut<'a, T>,
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, S> ParallelExtend<T> for MutSet<T, S>
+where
+    T: Eq + Hash + Send,
+    S: BuildHasher + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let items: Vec<T> = par_iter.into_par_iter().collect();
+        self.extend(items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::Hasher;
+
+    #[test]
+    fn insert_contains_and_len() {
+        let mut set = MutSet::new();
+
+        assert!(set.insert(1));
+        assert!(set.insert(2));
+        assert!(set.insert(3));
+        assert!(!set.insert(2));
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(!set.contains(&4));
+    }
+
+    #[test]
+    fn insert_existing_updates_value() {
+        #[derive(Debug)]
+        struct Item(i32, i32);
+
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Item {}
+        impl std::hash::Hash for Item {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        let mut set = MutSet::new();
+        set.insert(Item(1, 10));
+        set.insert(Item(1, 20));
+
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.get(&Item(1, 0)).unwrap().1, 20);
+    }
+
+    #[test]
+    fn get_and_get_mut() {
+        #[derive(Debug)]
+        struct Item(i32, i32);
+
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Item {}
+        impl std::hash::Hash for Item {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        let mut set = MutSet::new();
+        set.insert(Item(1, 10));
+        set.insert(Item(2, 20));
+
+        assert_eq!(set.get(&Item(1, 0)).unwrap().1, 10);
+        assert!(set.get(&Item(3, 0)).is_none());
+
+        set.get_mut(&Item(2, 0)).unwrap().1 = 99;
+        assert_eq!(set.get(&Item(2, 0)).unwrap().1, 99);
+    }
+
+    #[test]
+    fn remove_and_take() {
+        let mut set = MutSet::new();
+        set.insert(1);
+        set.insert(2);
+
+        assert_eq!(set.take(&1), Some(1));
+        assert_eq!(set.take(&1), None);
+        assert!(set.remove(&2));
+        assert!(!set.remove(&2));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn retain_keeps_matching_elements() {
+        let mut set: MutSet<i32> = (0..6).collect();
+        set.retain(|&mut value| value % 2 == 0);
+
+        let mut values: Vec<i32> = set.into_iter().collect();
+        values.sort();
+        assert_eq!(values, [0, 2, 4]);
+    }
+
+    #[test]
+    fn clear_empties_the_set() {
+        let mut set: MutSet<i32> = (0..3).collect();
+        set.clear();
+
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut set: MutSet<i32> = (1..=3).collect();
+
+        let mut values: Vec<i32> = set.iter().copied().collect();
+        values.sort();
+        assert_eq!(values, [1, 2, 3]);
+
+        for value in set.iter_mut() {
+            *value *= 10;
+        }
+        let mut scaled: Vec<i32> = set.into_iter().collect();
+        scaled.sort();
+        assert_eq!(scaled, [10, 20, 30]);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a: MutSet<i32> = [1, 2, 3].into_iter().collect();
+        let b: MutSet<i32> = [2, 3, 4].into_iter().collect();
+
+        let mut union: Vec<i32> = a.union(&b).copied().collect();
+        union.sort();
+        assert_eq!(union, [1, 2, 3, 4]);
+
+        let mut intersection: Vec<i32> = a.intersection(&b).copied().collect();
+        intersection.sort();
+        assert_eq!(intersection, [2, 3]);
+
+        let mut difference: Vec<i32> = a.difference(&b).copied().collect();
+        difference.sort();
+        assert_eq!(difference, [1]);
+
+        let mut symmetric_difference: Vec<i32> = a.symmetric_difference(&b).copied().collect();
+        symmetric_difference.sort();
+        assert_eq!(symmetric_difference, [1, 4]);
+    }
+
+    #[test]
+    fn subset_superset_disjoint() {
+        let a: MutSet<i32> = [1, 2].into_iter().collect();
+        let b: MutSet<i32> = [1, 2, 3].into_iter().collect();
+        let c: MutSet<i32> = [5, 6].into_iter().collect();
+
+        assert!(a.is_subset(&b));
+        assert!(b.is_superset(&a));
+        assert!(a.is_disjoint(&c));
+        assert!(!a.is_disjoint(&b));
+    }
+
+    #[test]
+    fn bit_operators() {
+        let a: MutSet<i32> = [1, 2, 3].into_iter().collect();
+        let b: MutSet<i32> = [2, 3, 4].into_iter().collect();
+
+        let mut or_v: Vec<i32> = (&a | &b).into_iter().collect();
+        or_v.sort();
+        assert_eq!(or_v, [1, 2, 3, 4]);
+
+        let mut and_v: Vec<i32> = (&a & &b).into_iter().collect();
+        and_v.sort();
+        assert_eq!(and_v, [2, 3]);
+
+        let mut sub_v: Vec<i32> = (&a - &b).into_iter().collect();
+        sub_v.sort();
+        assert_eq!(sub_v, [1]);
+
+        let mut xor_v: Vec<i32> = (&a ^ &b).into_iter().collect();
+        xor_v.sort();
+        assert_eq!(xor_v, [1, 4]);
+    }
+
+    #[test]
+    fn default_from_iter_and_extend() {
+        let mut set: MutSet<i32> = MutSet::default();
+        assert!(set.is_empty());
+
+        set.extend([1, 2, 3]);
+        let mut values: Vec<i32> = set.into_iter().collect();
+        values.sort();
+        assert_eq!(values, [1, 2, 3]);
+
+        let from_iter: MutSet<i32> = [4, 5].into_iter().collect();
+        assert_eq!(from_iter.len(), 2);
+    }
+
+    // A hasher that collapses every value onto the same bucket, so these
+    // tests exercise the `Vec<T>` collision-chaining path in `MutSet`
+    // instead of relying on lucky (or unlucky) `RandomState` hashes.
+    #[derive(Clone, Default)]
+    struct ConstantHasher;
+
+    impl BuildHasher for ConstantHasher {
+        type Hasher = ConstantHasherImpl;
+
+        fn build_hasher(&self) -> ConstantHasherImpl {
+            ConstantHasherImpl
+        }
+    }
+
+    struct ConstantHasherImpl;
+
+    impl Hasher for ConstantHasherImpl {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    #[test]
+    fn forced_collisions_still_behave_like_a_set() {
+        let mut set: MutSet<i32, ConstantHasher> = MutSet::with_hasher(ConstantHasher);
+
+        assert!(set.insert(1));
+        assert!(set.insert(2));
+        assert!(set.insert(3));
+        assert!(!set.insert(2));
+        assert_eq!(set.len(), 3);
+
+        assert!(set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+
+        assert_eq!(set.take(&2), Some(2));
+        assert_eq!(set.len(), 2);
+        assert!(!set.contains(&2));
+
+        let mut remaining: Vec<i32> = set.into_iter().collect();
+        remaining.sort();
+        assert_eq!(remaining, [1, 3]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let set: MutSet<i32> = [1, 2, 3].into_iter().collect();
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: MutSet<i32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.len(), 3);
+        for value in [1, 2, 3] {
+            assert!(restored.contains(&value));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn rayon_adapters() {
+        let mut set: MutSet<i32> = (0..100).collect();
+
+        let mut via_ref: Vec<i32> = set.par_iter().copied().collect();
+        via_ref.sort();
+        assert_eq!(via_ref, (0..100).collect::<Vec<_>>());
+
+        set.par_iter_mut().for_each(|value| *value += 1);
+
+        let mut via_owned: Vec<i32> = set.into_par_iter().collect();
+        via_owned.sort();
+        assert_eq!(via_owned, (1..101).collect::<Vec<_>>());
+    }
+}